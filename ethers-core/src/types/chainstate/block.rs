@@ -1,7 +1,17 @@
 // Taken from https://github.com/tomusdrw/rust-web3/blob/master/src/types/block.rs
-use crate::types::{Address, Bloom, Bytes, H256, U256, U64};
+use crate::types::{Address, Bloom, Bytes, Transaction, H256, U256, U64};
+#[cfg(not(feature = "celo"))]
+use crate::utils::keccak256;
+#[cfg(not(feature = "celo"))]
+use rlp::RlpStream;
 use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
 
+/// The multiplier relating the block gas limit to the gas target used by the
+/// EIP-1559 base fee algorithm.
+const ELASTICITY_MULTIPLIER: u64 = 2;
+/// The maximum fraction by which the base fee can change between two blocks.
+const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
 #[cfg(feature = "celo")]
 ///
@@ -77,6 +87,10 @@ pub struct Block<TX> {
     /// Nonce
     #[cfg(not(feature = "celo"))]
     pub nonce: Option<U64>,
+    /// Base fee per gas. Present from the London hard-fork onwards; `None` for
+    /// pre-London blocks so they keep deserializing.
+    #[serde(rename = "baseFeePerGas")]
+    pub base_fee_per_gas: Option<U256>,
 
     #[cfg(feature = "celo")]
     #[cfg_attr(docsrs, doc(cfg(feature = "celo")))]
@@ -84,17 +98,288 @@ pub struct Block<TX> {
     pub randomness: Randomness,
 }
 
+/// The consensus header of a block, without its transaction list.
+///
+/// This mirrors the header portion of the `eth_getBlockByHash` response and is
+/// useful for light-client and header-sync flows that do not need to decode the
+/// (potentially large) `Vec<TX>` payload.
+#[cfg(not(feature = "celo"))]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Header {
+    /// Hash of the block
+    pub hash: Option<H256>,
+    /// Hash of the parent
+    #[serde(rename = "parentHash")]
+    pub parent_hash: H256,
+    /// Hash of the uncles
+    #[serde(rename = "sha3Uncles")]
+    pub uncles_hash: H256,
+    /// Miner/author's address.
+    #[serde(rename = "miner")]
+    pub author: Address,
+    /// State root hash
+    #[serde(rename = "stateRoot")]
+    pub state_root: H256,
+    /// Transactions root hash
+    #[serde(rename = "transactionsRoot")]
+    pub transactions_root: H256,
+    /// Transactions receipts root hash
+    #[serde(rename = "receiptsRoot")]
+    pub receipts_root: H256,
+    /// Block number. None if pending.
+    pub number: Option<U64>,
+    /// Gas Used
+    #[serde(rename = "gasUsed")]
+    pub gas_used: U256,
+    /// Gas Limit
+    #[serde(rename = "gasLimit")]
+    pub gas_limit: U256,
+    /// Extra data
+    #[serde(rename = "extraData")]
+    pub extra_data: Bytes,
+    /// Logs bloom
+    #[serde(rename = "logsBloom")]
+    pub logs_bloom: Option<Bloom>,
+    /// Timestamp
+    pub timestamp: U256,
+    /// Difficulty
+    pub difficulty: U256,
+    /// Mix Hash
+    #[serde(rename = "mixHash")]
+    pub mix_hash: Option<H256>,
+    /// Nonce
+    pub nonce: Option<U64>,
+    /// Base fee per gas. `None` for pre-London blocks.
+    #[serde(rename = "baseFeePerGas")]
+    pub base_fee_per_gas: Option<U256>,
+}
+
+#[cfg(not(feature = "celo"))]
+impl Header {
+    /// Computes the block hash by RLP-encoding the canonical header fields in
+    /// protocol order and keccak256-hashing them. The `base_fee_per_gas` field
+    /// is appended only when present, so pre-London blocks hash correctly.
+    ///
+    /// Only pre-London and London headers are supported. The post-Shanghai
+    /// `withdrawalsRoot` and post-Cancun `blobGasUsed`/`excessBlobGas`/
+    /// `parentBeaconBlockRoot` fields are not encoded, so the returned hash
+    /// does not match for blocks that carry them.
+    pub fn hash(&self) -> H256 {
+        let mut rlp = RlpStream::new();
+        let fields = if self.base_fee_per_gas.is_some() { 16 } else { 15 };
+        rlp.begin_list(fields);
+        rlp.append(&self.parent_hash);
+        rlp.append(&self.uncles_hash);
+        rlp.append(&self.author);
+        rlp.append(&self.state_root);
+        rlp.append(&self.transactions_root);
+        rlp.append(&self.receipts_root);
+        rlp.append(&self.logs_bloom.unwrap_or_default());
+        rlp.append(&self.difficulty);
+        rlp.append(&self.number.unwrap_or_default());
+        rlp.append(&self.gas_limit);
+        rlp.append(&self.gas_used);
+        rlp.append(&self.timestamp);
+        rlp.append(&self.extra_data.as_ref());
+        rlp.append(&self.mix_hash.unwrap_or_default());
+        // The consensus nonce is a fixed 8-byte field; encode it as such so
+        // leading zero bytes (e.g. post-merge `0x0000000000000000`) survive,
+        // rather than letting the `U64` integer encoding strip them.
+        let mut nonce = [0u8; 8];
+        self.nonce.unwrap_or_default().to_big_endian(&mut nonce);
+        rlp.append(&nonce.as_ref());
+        if let Some(base_fee) = self.base_fee_per_gas {
+            rlp.append(&base_fee);
+        }
+        keccak256(rlp.out().as_ref()).into()
+    }
+}
+
+#[cfg(not(feature = "celo"))]
+impl<TX> Block<TX> {
+    /// Returns the consensus [`Header`] of this block, leaving its transaction
+    /// list untouched.
+    pub fn header(&self) -> Header {
+        Header {
+            hash: self.hash,
+            parent_hash: self.parent_hash,
+            uncles_hash: self.uncles_hash,
+            author: self.author,
+            state_root: self.state_root,
+            transactions_root: self.transactions_root,
+            receipts_root: self.receipts_root,
+            number: self.number,
+            gas_used: self.gas_used,
+            gas_limit: self.gas_limit,
+            extra_data: self.extra_data.clone(),
+            logs_bloom: self.logs_bloom,
+            timestamp: self.timestamp,
+            difficulty: self.difficulty,
+            mix_hash: self.mix_hash,
+            nonce: self.nonce,
+            base_fee_per_gas: self.base_fee_per_gas,
+        }
+    }
+
+    /// RLP-encodes the block's header and returns its keccak256 hash. This is
+    /// the hash the node should have returned in the `hash` field.
+    pub fn compute_hash(&self) -> H256 {
+        self.header().hash()
+    }
+
+    /// Verifies that the node-provided `hash` matches the hash computed from the
+    /// header fields. Returns `false` when the block carries no `hash`.
+    ///
+    /// Hashing only covers pre-London and London headers (see [`Header::hash`]);
+    /// for post-Shanghai/Cancun blocks, whose headers carry extra fields this
+    /// does not encode, it returns `false` even for honest responses.
+    pub fn verify_hash(&self) -> bool {
+        self.hash.map(|hash| hash == self.compute_hash()).unwrap_or(false)
+    }
+
+    /// Predicts the base fee of the block following this one, per the EIP-1559
+    /// recurrence. Returns `None` for pre-London blocks, which carry no
+    /// `base_fee_per_gas`.
+    pub fn next_block_base_fee(&self) -> Option<U256> {
+        let base_fee = self.base_fee_per_gas?;
+        let gas_target = self.gas_limit / ELASTICITY_MULTIPLIER;
+
+        if self.gas_used == gas_target {
+            return Some(base_fee);
+        }
+
+        let denominator = U256::from(BASE_FEE_MAX_CHANGE_DENOMINATOR);
+        if self.gas_used > gas_target {
+            let delta = std::cmp::max(
+                base_fee * (self.gas_used - gas_target) / gas_target / denominator,
+                U256::one(),
+            );
+            Some(base_fee + delta)
+        } else {
+            let delta = base_fee * (gas_target - self.gas_used) / gas_target / denominator;
+            Some(base_fee.saturating_sub(delta))
+        }
+    }
+}
+
+/// The transaction payload of a block, as returned by `eth_getBlockBy*`.
+///
+/// Depending on the `full_transactions` flag the node returns either an array
+/// of transaction hashes or an array of full transaction objects; this enum
+/// decodes both shapes through a single path.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BlockTransactions {
+    /// Only the transaction hashes are returned.
+    Hashes(Vec<H256>),
+    /// The full transaction objects are returned.
+    Full(Vec<Transaction>),
+}
+
+impl Default for BlockTransactions {
+    fn default() -> Self {
+        BlockTransactions::Hashes(Vec::new())
+    }
+}
+
+impl BlockTransactions {
+    /// Returns the transaction hashes, if the block was fetched without full
+    /// transactions.
+    pub fn hashes(&self) -> Option<&Vec<H256>> {
+        match self {
+            BlockTransactions::Hashes(hashes) => Some(hashes),
+            BlockTransactions::Full(_) => None,
+        }
+    }
+
+    /// Returns the full transactions, if the block was fetched with full
+    /// transactions.
+    pub fn full(&self) -> Option<&Vec<Transaction>> {
+        match self {
+            BlockTransactions::Full(txs) => Some(txs),
+            BlockTransactions::Hashes(_) => None,
+        }
+    }
+
+    /// Returns the number of transactions in the block.
+    pub fn len(&self) -> usize {
+        match self {
+            BlockTransactions::Hashes(hashes) => hashes.len(),
+            BlockTransactions::Full(txs) => txs.len(),
+        }
+    }
+
+    /// Returns whether the block contains no transactions.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl From<Vec<H256>> for BlockTransactions {
+    fn from(hashes: Vec<H256>) -> Self {
+        BlockTransactions::Hashes(hashes)
+    }
+}
+
+impl From<Vec<Transaction>> for BlockTransactions {
+    fn from(txs: Vec<Transaction>) -> Self {
+        BlockTransactions::Full(txs)
+    }
+}
+
+impl From<Block<H256>> for BlockTransactions {
+    fn from(block: Block<H256>) -> Self {
+        BlockTransactions::Hashes(block.transactions)
+    }
+}
+
+impl From<Block<Transaction>> for BlockTransactions {
+    fn from(block: Block<Transaction>) -> Self {
+        BlockTransactions::Full(block.transactions)
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 /// A Block Hash or Block Number
 pub enum BlockId {
-    // TODO: May want to expand this to include the requireCanonical field
-    // https://github.com/ethereum/EIPs/blob/master/EIPS/eip-1898.md
-    /// A block hash
-    Hash(H256),
+    /// A block hash and an optional bool that specifies whether the block must
+    /// belong to the canonical chain (EIP-1898).
+    Hash(RpcBlockHash),
     /// A block number
     Number(BlockNumber),
 }
 
+/// A block hash which may optionally require the block to be part of the
+/// canonical chain, per EIP-1898.
+///
+/// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-1898.md
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RpcBlockHash {
+    /// A block hash
+    pub block_hash: H256,
+    /// Whether the block must be a canonical block
+    pub require_canonical: Option<bool>,
+}
+
+impl RpcBlockHash {
+    /// Creates a [`RpcBlockHash`] from a hash and an optional canonicity flag.
+    pub fn from_hash(block_hash: H256, require_canonical: Option<bool>) -> Self {
+        RpcBlockHash { block_hash, require_canonical }
+    }
+}
+
+impl From<H256> for RpcBlockHash {
+    fn from(hash: H256) -> Self {
+        RpcBlockHash { block_hash: hash, require_canonical: None }
+    }
+}
+
+impl From<RpcBlockHash> for H256 {
+    fn from(hash: RpcBlockHash) -> Self {
+        hash.block_hash
+    }
+}
+
 impl From<u64> for BlockId {
     fn from(num: u64) -> Self {
         BlockNumber::Number(num.into()).into()
@@ -115,6 +400,12 @@ impl From<BlockNumber> for BlockId {
 
 impl From<H256> for BlockId {
     fn from(hash: H256) -> Self {
+        BlockId::Hash(hash.into())
+    }
+}
+
+impl From<RpcBlockHash> for BlockId {
+    fn from(hash: RpcBlockHash) -> Self {
         BlockId::Hash(hash)
     }
 }
@@ -125,9 +416,13 @@ impl Serialize for BlockId {
         S: Serializer,
     {
         match *self {
-            BlockId::Hash(ref x) => {
-                let mut s = serializer.serialize_struct("BlockIdEip1898", 1)?;
-                s.serialize_field("blockHash", &format!("{:?}", x))?;
+            BlockId::Hash(RpcBlockHash { ref block_hash, ref require_canonical }) => {
+                let size = if require_canonical.is_some() { 2 } else { 1 };
+                let mut s = serializer.serialize_struct("BlockIdEip1898", size)?;
+                s.serialize_field("blockHash", &format!("{:?}", block_hash))?;
+                if let Some(require_canonical) = require_canonical {
+                    s.serialize_field("requireCanonical", require_canonical)?;
+                }
                 s.end()
             }
             BlockId::Number(ref num) => num.serialize(serializer),
@@ -185,6 +480,156 @@ mod tests {
         let block = r#"{"number":"0x3","hash":"0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972","parentHash":"0x689c70c080ca22bc0e681694fa803c1aba16a69c8b6368fed5311d279eb9de90","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","nonce":"0x0000000000000000","sha3Uncles":"0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347","logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","transactionsRoot":"0x7270c1c4440180f2bd5215809ee3d545df042b67329499e1ab97eb759d31610d","stateRoot":"0x29f32984517a7d25607da485b23cefabfd443751422ca7e603395e1de9bc8a4b","receiptsRoot":"0x056b23fbba480696b65fe5a59b8f2148a1299103c4f57df839233af2cf4ca2d2","miner":"0x0000000000000000000000000000000000000000","difficulty":"0x0","totalDifficulty":"0x0","extraData":"0x","size":"0x3e8","gasLimit":"0x6691b7","gasUsed":"0x5208","timestamp":"0x5ecedbb9","transactions":[{"hash":"0xc3c5f700243de37ae986082fd2af88d2a7c2752a0c0f7b9d6ac47c729d45e067","nonce":"0x2","blockHash":"0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972","blockNumber":"0x3","transactionIndex":"0x0","from":"0xfdcedc3bfca10ecb0890337fbdd1977aba84807a","to":"0xdca8ce283150ab773bcbeb8d38289bdb5661de1e","value":"0x0","gas":"0x15f90","gasPrice":"0x4a817c800","input":"0x","v":"0x25","r":"0x19f2694eb9113656dbea0b925e2e7ceb43df83e601c4116aee9c0dd99130be88","s":"0x73e5764b324a4f7679d890a198ba658ba1c8cd36983ff9797e10b1b89dbb448e"}],"uncles":[]}"#;
         let _block: Block<Transaction> = serde_json::from_str(&block).unwrap();
     }
+
+    #[test]
+    fn deserialize_blk_with_typed_txs() {
+        // A post-London block mixing a legacy tx (no `type`), an EIP-2930
+        // access-list tx (`type` 0x1 with `accessList`), and an EIP-1559
+        // dynamic-fee tx (`type` 0x2 with `maxFeePerGas`/`maxPriorityFeePerGas`).
+        let block = r#"{"number":"0x3","hash":"0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972","parentHash":"0x689c70c080ca22bc0e681694fa803c1aba16a69c8b6368fed5311d279eb9de90","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","nonce":"0x0000000000000000","sha3Uncles":"0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347","logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","transactionsRoot":"0x7270c1c4440180f2bd5215809ee3d545df042b67329499e1ab97eb759d31610d","stateRoot":"0x29f32984517a7d25607da485b23cefabfd443751422ca7e603395e1de9bc8a4b","receiptsRoot":"0x056b23fbba480696b65fe5a59b8f2148a1299103c4f57df839233af2cf4ca2d2","miner":"0x0000000000000000000000000000000000000000","difficulty":"0x0","totalDifficulty":"0x0","extraData":"0x","size":"0x3e8","gasLimit":"0x6691b7","gasUsed":"0x5208","timestamp":"0x5ecedbb9","baseFeePerGas":"0x7","transactions":[{"hash":"0xc3c5f700243de37ae986082fd2af88d2a7c2752a0c0f7b9d6ac47c729d45e067","nonce":"0x2","blockHash":"0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972","blockNumber":"0x3","transactionIndex":"0x0","from":"0xfdcedc3bfca10ecb0890337fbdd1977aba84807a","to":"0xdca8ce283150ab773bcbeb8d38289bdb5661de1e","value":"0x0","gas":"0x15f90","gasPrice":"0x4a817c800","input":"0x","v":"0x25","r":"0x19f2694eb9113656dbea0b925e2e7ceb43df83e601c4116aee9c0dd99130be88","s":"0x73e5764b324a4f7679d890a198ba658ba1c8cd36983ff9797e10b1b89dbb448e"},{"hash":"0x1f5d7ea0f6f7e6b1b0b0e6b1b0b0e6b1b0b0e6b1b0b0e6b1b0b0e6b1b0b0e6b1","nonce":"0x3","blockHash":"0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972","blockNumber":"0x3","transactionIndex":"0x1","from":"0xfdcedc3bfca10ecb0890337fbdd1977aba84807a","to":"0xdca8ce283150ab773bcbeb8d38289bdb5661de1e","value":"0x0","gas":"0x15f90","gasPrice":"0x4a817c800","input":"0x","type":"0x1","accessList":[{"address":"0xdca8ce283150ab773bcbeb8d38289bdb5661de1e","storageKeys":["0x0000000000000000000000000000000000000000000000000000000000000000"]}],"v":"0x0","r":"0x19f2694eb9113656dbea0b925e2e7ceb43df83e601c4116aee9c0dd99130be88","s":"0x73e5764b324a4f7679d890a198ba658ba1c8cd36983ff9797e10b1b89dbb448e"},{"hash":"0x2f5d7ea0f6f7e6b1b0b0e6b1b0b0e6b1b0b0e6b1b0b0e6b1b0b0e6b1b0b0e6b2","nonce":"0x4","blockHash":"0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972","blockNumber":"0x3","transactionIndex":"0x2","from":"0xfdcedc3bfca10ecb0890337fbdd1977aba84807a","to":"0xdca8ce283150ab773bcbeb8d38289bdb5661de1e","value":"0x0","gas":"0x15f90","input":"0x","type":"0x2","maxFeePerGas":"0x4a817c800","maxPriorityFeePerGas":"0x3b9aca00","accessList":[],"v":"0x1","r":"0x19f2694eb9113656dbea0b925e2e7ceb43df83e601c4116aee9c0dd99130be88","s":"0x73e5764b324a4f7679d890a198ba658ba1c8cd36983ff9797e10b1b89dbb448e"}],"uncles":[]}"#;
+        let block: Block<Transaction> = serde_json::from_str(&block).unwrap();
+        let txs = &block.transactions;
+
+        // Legacy tx: no `type` in the payload, defaults to `None`.
+        assert_eq!(txs[0].transaction_type, None);
+
+        // EIP-2930 access-list tx.
+        assert_eq!(txs[1].transaction_type, Some(1u64.into()));
+        let access_list = txs[1].access_list.clone().unwrap();
+        assert_eq!(access_list.len(), 1);
+        assert_eq!(
+            access_list[0].0,
+            "0xdca8ce283150ab773bcbeb8d38289bdb5661de1e".parse().unwrap()
+        );
+        assert_eq!(
+            access_list[0].1,
+            vec!["0x0000000000000000000000000000000000000000000000000000000000000000"
+                .parse::<H256>()
+                .unwrap()]
+        );
+
+        // EIP-1559 dynamic-fee tx.
+        assert_eq!(txs[2].transaction_type, Some(2u64.into()));
+        assert_eq!(txs[2].max_fee_per_gas, Some(0x4a817c800u64.into()));
+        assert_eq!(txs[2].max_priority_fee_per_gas, Some(0x3b9aca00u64.into()));
+    }
+
+    #[test]
+    fn pending_block() {
+        // A pending block carries a `baseFeePerGas` but has `number`, `hash`,
+        // `nonce` and `logsBloom` unset.
+        let block = r#"{"number":null,"hash":null,"parentHash":"0x689c70c080ca22bc0e681694fa803c1aba16a69c8b6368fed5311d279eb9de90","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","nonce":null,"sha3Uncles":"0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347","logsBloom":null,"transactionsRoot":"0x7270c1c4440180f2bd5215809ee3d545df042b67329499e1ab97eb759d31610d","stateRoot":"0x29f32984517a7d25607da485b23cefabfd443751422ca7e603395e1de9bc8a4b","receiptsRoot":"0x056b23fbba480696b65fe5a59b8f2148a1299103c4f57df839233af2cf4ca2d2","miner":"0x0000000000000000000000000000000000000000","difficulty":"0x0","totalDifficulty":null,"extraData":"0x","size":null,"gasLimit":"0x1c9c380","gasUsed":"0x0","timestamp":"0x5ecedbb9","baseFeePerGas":"0x3b9aca00","transactions":[],"uncles":[]}"#;
+        let block: Block<TxHash> = serde_json::from_str(&block).unwrap();
+        assert_eq!(block.base_fee_per_gas, Some(0x3b9aca00u64.into()));
+        assert!(block.number.is_none());
+    }
+
+    #[test]
+    fn deserialize_block_transactions() {
+        let hashes = r#"["0xc3c5f700243de37ae986082fd2af88d2a7c2752a0c0f7b9d6ac47c729d45e067"]"#;
+        let txs: BlockTransactions = serde_json::from_str(hashes).unwrap();
+        assert_eq!(txs.len(), 1);
+        assert!(txs.hashes().is_some());
+        assert!(txs.full().is_none());
+
+        let full = r#"[{"hash":"0xc3c5f700243de37ae986082fd2af88d2a7c2752a0c0f7b9d6ac47c729d45e067","nonce":"0x2","blockHash":"0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972","blockNumber":"0x3","transactionIndex":"0x0","from":"0xfdcedc3bfca10ecb0890337fbdd1977aba84807a","to":"0xdca8ce283150ab773bcbeb8d38289bdb5661de1e","value":"0x0","gas":"0x15f90","gasPrice":"0x4a817c800","input":"0x","v":"0x25","r":"0x19f2694eb9113656dbea0b925e2e7ceb43df83e601c4116aee9c0dd99130be88","s":"0x73e5764b324a4f7679d890a198ba658ba1c8cd36983ff9797e10b1b89dbb448e"}]"#;
+        let txs: BlockTransactions = serde_json::from_str(full).unwrap();
+        assert_eq!(txs.len(), 1);
+        assert!(txs.full().is_some());
+        assert!(txs.hashes().is_none());
+    }
+
+    #[test]
+    fn compute_and_verify_block_hash() {
+        // Mainnet genesis header: RLP-encoding these fields and keccak256-ing
+        // them must reproduce the canonical genesis hash. The `0x...42` nonce
+        // has leading zero bytes, so this also pins the fixed-width encoding.
+        let header = Header {
+            parent_hash: "0x0000000000000000000000000000000000000000000000000000000000000000"
+                .parse()
+                .unwrap(),
+            uncles_hash: "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347"
+                .parse()
+                .unwrap(),
+            author: "0x0000000000000000000000000000000000000000".parse().unwrap(),
+            state_root: "0xd7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544"
+                .parse()
+                .unwrap(),
+            transactions_root:
+                "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+                    .parse()
+                    .unwrap(),
+            receipts_root: "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+                .parse()
+                .unwrap(),
+            number: Some(0u64.into()),
+            gas_used: 0u64.into(),
+            gas_limit: 0x1388u64.into(),
+            extra_data: "0x11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa"
+                .parse()
+                .unwrap(),
+            logs_bloom: Some(Default::default()),
+            timestamp: 0u64.into(),
+            difficulty: 0x400000000u64.into(),
+            mix_hash: Some(
+                "0x0000000000000000000000000000000000000000000000000000000000000000"
+                    .parse()
+                    .unwrap(),
+            ),
+            nonce: Some(0x42u64.into()),
+            base_fee_per_gas: None,
+            hash: None,
+        };
+
+        let expected: H256 =
+            "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                .parse()
+                .unwrap();
+        assert_eq!(header.hash(), expected);
+
+        let mut block = Block::<TxHash>::default();
+        block.parent_hash = header.parent_hash;
+        block.uncles_hash = header.uncles_hash;
+        block.author = header.author;
+        block.state_root = header.state_root;
+        block.transactions_root = header.transactions_root;
+        block.receipts_root = header.receipts_root;
+        block.number = header.number;
+        block.gas_used = header.gas_used;
+        block.gas_limit = header.gas_limit;
+        block.extra_data = header.extra_data.clone();
+        block.logs_bloom = header.logs_bloom;
+        block.timestamp = header.timestamp;
+        block.difficulty = header.difficulty;
+        block.mix_hash = header.mix_hash;
+        block.nonce = header.nonce;
+        block.hash = Some(expected);
+
+        assert_eq!(block.compute_hash(), expected);
+        assert!(block.verify_hash());
+    }
+
+    #[test]
+    fn next_block_base_fee() {
+        let mut block = Block::<TxHash>::default();
+        // pre-London: no base fee to extrapolate from
+        assert_eq!(block.next_block_base_fee(), None);
+
+        block.base_fee_per_gas = Some(1_000_000_000u64.into());
+        block.gas_limit = 20_000_000u64.into();
+
+        // gas_used at target leaves the base fee unchanged
+        block.gas_used = 10_000_000u64.into();
+        assert_eq!(block.next_block_base_fee(), Some(1_000_000_000u64.into()));
+
+        // full block raises the base fee by 1/8th
+        block.gas_used = 20_000_000u64.into();
+        assert_eq!(block.next_block_base_fee(), Some(1_125_000_000u64.into()));
+
+        // empty block lowers the base fee by 1/8th
+        block.gas_used = 0u64.into();
+        assert_eq!(block.next_block_base_fee(), Some(875_000_000u64.into()));
+    }
 }
 
 #[cfg(test)]